@@ -0,0 +1,121 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, AccountDeserialize, InstructionData, ToAccountMetas};
+use solana_hash::Hash;
+use solana_keypair::Keypair;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+pub use calci::{CalciResult, ErrorCode, ID as PROGRAM_ID};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error(transparent)]
+    Rpc(#[from] Box<solana_rpc_client_api::client_error::Error>),
+    #[error(transparent)]
+    Anchor(#[from] anchor_lang::error::Error),
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+pub fn initialize(fee_payer: &Pubkey, cacli_acc: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: calci::accounts::Initialize {
+            fee_payer: *fee_payer,
+            cacli_acc: *cacli_acc,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: calci::instruction::Initialize {}.data(),
+    }
+}
+
+pub fn add(cacli_acc: &Pubkey, a: u8, b: u8) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: calci::accounts::Add {
+            cacli_acc: *cacli_acc,
+        }
+        .to_account_metas(None),
+        data: calci::instruction::Add { a, b }.data(),
+    }
+}
+
+pub fn sub(cacli_acc: &Pubkey, a: u8, b: u8) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: calci::accounts::Sub {
+            cacli_acc: *cacli_acc,
+        }
+        .to_account_metas(None),
+        data: calci::instruction::Sub { a, b }.data(),
+    }
+}
+
+pub fn div(cacli_acc: &Pubkey, a: u8, b: u8) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: calci::accounts::Div {
+            cacli_acc: *cacli_acc,
+        }
+        .to_account_metas(None),
+        data: calci::instruction::Div { a, b }.data(),
+    }
+}
+
+pub fn get_result(cacli_acc: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: calci::accounts::GetResult {
+            cacli_acc: *cacli_acc,
+        }
+        .to_account_metas(None),
+        data: calci::instruction::GetResult {}.data(),
+    }
+}
+
+pub fn close(payer: &Pubkey, cacli_acc: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: calci::accounts::Close {
+            payer: *payer,
+            cacli_acc: *cacli_acc,
+        }
+        .to_account_metas(None),
+        data: calci::instruction::Close {}.data(),
+    }
+}
+
+/// The calculator account is a fresh keypair, so it has to co-sign its own creation.
+pub fn build_initialize_tx(
+    fee_payer: &Keypair,
+    cacli_acc: &Keypair,
+    recent_blockhash: Hash,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[initialize(&fee_payer.pubkey(), &cacli_acc.pubkey())],
+        Some(&fee_payer.pubkey()),
+        &[fee_payer, cacli_acc],
+        recent_blockhash,
+    )
+}
+
+pub fn build_tx(
+    fee_payer: &Keypair,
+    instructions: &[Instruction],
+    recent_blockhash: Hash,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        instructions,
+        Some(&fee_payer.pubkey()),
+        &[fee_payer],
+        recent_blockhash,
+    )
+}
+
+pub fn fetch_result(rpc: &RpcClient, cacli_acc: &Pubkey) -> Result<CalciResult> {
+    let data = rpc.get_account_data(cacli_acc).map_err(Box::new)?;
+    Ok(CalciResult::try_deserialize(&mut data.as_slice())?)
+}
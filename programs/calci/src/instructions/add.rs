@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::CalciResult;
+
+#[derive(Accounts)]
+pub struct Add<'info> {
+    #[account(mut)]
+    cacli_acc: Account<'info, CalciResult>,
+}
+
+pub fn handler(ctx: Context<Add>, a: u8, b: u8) -> Result<()> {
+    ctx.accounts.cacli_acc.calci_result = a.checked_add(b).ok_or(ErrorCode::ArithmeticOverflow)?;
+    msg!(
+        "Addition Result is: {:?}",
+        ctx.accounts.cacli_acc.calci_result
+    );
+    Ok(())
+}
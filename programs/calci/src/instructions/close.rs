@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::CalciResult;
+
+#[derive(Accounts)]
+pub struct Close<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(mut, has_one = payer @ ErrorCode::NotPayer, close = payer)]
+    cacli_acc: Account<'info, CalciResult>,
+}
+
+pub fn handler(ctx: Context<Close>) -> Result<()> {
+    msg!(
+        "Closed calculator account: {:?}",
+        ctx.accounts.cacli_acc.key()
+    );
+    Ok(())
+}
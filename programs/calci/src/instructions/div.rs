@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::CalciResult;
+
+#[derive(Accounts)]
+pub struct Div<'info> {
+    #[account(mut)]
+    cacli_acc: Account<'info, CalciResult>,
+}
+
+pub fn handler(ctx: Context<Div>, a: u8, b: u8) -> Result<()> {
+    require!(b != 0, ErrorCode::DivisionByZero);
+    ctx.accounts.cacli_acc.calci_result = a / b;
+    msg!(
+        "Division Result is: {:?}",
+        ctx.accounts.cacli_acc.calci_result
+    );
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CalciResult;
+
+#[derive(Accounts)]
+pub struct GetResult<'info> {
+    cacli_acc: Account<'info, CalciResult>,
+}
+
+pub fn handler(ctx: Context<GetResult>) -> Result<u8> {
+    Ok(ctx.accounts.cacli_acc.calci_result)
+}
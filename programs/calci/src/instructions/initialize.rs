@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CalciResult;
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    fee_payer: Signer<'info>,
+
+    #[account(init, space = 8 + CalciResult::INIT_SPACE, payer = fee_payer)]
+    cacli_acc: Account<'info, CalciResult>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Initialize>) -> Result<()> {
+    ctx.accounts.cacli_acc.calci_result = 0;
+    ctx.accounts.cacli_acc.payer = ctx.accounts.fee_payer.key();
+    msg!("Greetings from Calculator Program: {:?}", ctx.program_id);
+    msg!("Payer: {:?}", ctx.accounts.cacli_acc.payer);
+    Ok(())
+}
@@ -0,0 +1,15 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod add;
+pub mod close;
+pub mod div;
+pub mod get_result;
+pub mod initialize;
+pub mod sub;
+
+pub use add::*;
+pub use close::*;
+pub use div::*;
+pub use get_result::*;
+pub use initialize::*;
+pub use sub::*;
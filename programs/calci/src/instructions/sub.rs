@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::CalciResult;
+
+#[derive(Accounts)]
+pub struct Sub<'info> {
+    #[account(mut)]
+    cacli_acc: Account<'info, CalciResult>,
+}
+
+pub fn handler(ctx: Context<Sub>, a: u8, b: u8) -> Result<()> {
+    ctx.accounts.cacli_acc.calci_result = a.checked_sub(b).ok_or(ErrorCode::ArithmeticUnderflow)?;
+    msg!(
+        "Subtraction Result is: {:?}",
+        ctx.accounts.cacli_acc.calci_result
+    );
+    Ok(())
+}
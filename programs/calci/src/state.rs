@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CalciResult {
+    pub calci_result: u8, // 1 byte
+    pub payer: Pubkey,    // 32 bytes
+}
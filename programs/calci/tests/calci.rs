@@ -0,0 +1,256 @@
+use anchor_lang::prelude::{Pubkey, Rent};
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::error::InstructionError;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, Space, ToAccountMetas};
+use calci::{CalciResult, ErrorCode};
+use solana_account::Account;
+use solana_hash::Hash;
+use solana_keypair::Keypair;
+use solana_program_test::{processor, BanksClient, BanksClientError, ProgramTest};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+// The program runs as a native processor here, where Anchor's CPIs and return
+// data are not available. initialize creates its account through a system
+// program CPI, so these tests seed the CalciResult account directly;
+// initialize and get_result are covered by the TypeScript suite against the
+// deployed program.
+
+// The Anchor entrypoint ties the account slice lifetime to the AccountInfo
+// lifetime, which processor! can't express, so hand it a leaked copy.
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    calci::entry(program_id, accounts, data)
+}
+
+fn calci_account(owner: Pubkey, calci_result: u8, payer: Pubkey) -> Account {
+    let mut data = Vec::with_capacity(8 + CalciResult::INIT_SPACE);
+    CalciResult {
+        calci_result,
+        payer,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+async fn start(cacli_acc: Option<(Pubkey, Account)>) -> (BanksClient, Keypair) {
+    let mut program_test = ProgramTest::new("calci", calci::ID, processor!(process_instruction));
+    program_test.prefer_bpf(false);
+    if let Some((address, account)) = cacli_acc {
+        program_test.add_account(address, account);
+    }
+    let (banks, payer, _) = program_test.start().await;
+    (banks, payer)
+}
+
+async fn start_initialized(calci_result: u8) -> (BanksClient, Keypair, Pubkey) {
+    let cacli_acc = Pubkey::new_unique();
+    let account = calci_account(calci::ID, calci_result, Pubkey::new_unique());
+    let (banks, payer) = start(Some((cacli_acc, account))).await;
+    (banks, payer, cacli_acc)
+}
+
+// add, sub and div all take the same single calculator account.
+fn instruction(cacli_acc: Pubkey, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: calci::ID,
+        accounts: calci::accounts::Add { cacli_acc }.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn transaction(payer: &Keypair, ix: Instruction, blockhash: Hash) -> Transaction {
+    Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], blockhash)
+}
+
+async fn process(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let blockhash = banks.get_latest_blockhash().await?;
+    banks
+        .process_transaction(transaction(payer, ix, blockhash))
+        .await
+}
+
+async fn stored_result(banks: &mut BanksClient, cacli_acc: Pubkey) -> u8 {
+    let account = banks.get_account(cacli_acc).await.unwrap().unwrap();
+    CalciResult::try_deserialize(&mut account.data.as_slice())
+        .unwrap()
+        .calci_result
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, expected: u32) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(0, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected)
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn operations_store_result() {
+    let (mut banks, payer, cacli_acc) = start_initialized(0).await;
+
+    let ix = instruction(cacli_acc, calci::instruction::Add { a: 10, b: 20 });
+    process(&mut banks, &payer, ix).await.unwrap();
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 30);
+
+    let ix = instruction(cacli_acc, calci::instruction::Sub { a: 50, b: 15 });
+    process(&mut banks, &payer, ix).await.unwrap();
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 35);
+
+    let ix = instruction(cacli_acc, calci::instruction::Div { a: 100, b: 5 });
+    process(&mut banks, &payer, ix).await.unwrap();
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 20);
+}
+
+#[tokio::test]
+async fn add_overflow_fails() {
+    let (mut banks, payer, cacli_acc) = start_initialized(7).await;
+
+    let ix = instruction(cacli_acc, calci::instruction::Add { a: 200, b: 100 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(result, ErrorCode::ArithmeticOverflow.into());
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 7);
+}
+
+#[tokio::test]
+async fn sub_underflow_fails() {
+    let (mut banks, payer, cacli_acc) = start_initialized(7).await;
+
+    let ix = instruction(cacli_acc, calci::instruction::Sub { a: 15, b: 50 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(result, ErrorCode::ArithmeticUnderflow.into());
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 7);
+}
+
+#[tokio::test]
+async fn div_by_zero_fails() {
+    let (mut banks, payer, cacli_acc) = start_initialized(7).await;
+
+    let ix = instruction(cacli_acc, calci::instruction::Div { a: 10, b: 0 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(result, ErrorCode::DivisionByZero.into());
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 7);
+}
+
+#[tokio::test]
+async fn uninitialized_account_fails() {
+    let (mut banks, payer) = start(None).await;
+
+    let ix = instruction(Pubkey::new_unique(), calci::instruction::Add { a: 1, b: 2 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::AccountNotInitialized.into(),
+    );
+}
+
+#[tokio::test]
+async fn account_owned_by_other_program_fails() {
+    let cacli_acc = Pubkey::new_unique();
+    let account = calci_account(Pubkey::new_unique(), 0, Pubkey::new_unique());
+    let (mut banks, payer) = start(Some((cacli_acc, account))).await;
+
+    let ix = instruction(cacli_acc, calci::instruction::Add { a: 1, b: 2 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into(),
+    );
+}
+
+fn close_instruction(payer: Pubkey, cacli_acc: Pubkey) -> Instruction {
+    Instruction {
+        program_id: calci::ID,
+        accounts: calci::accounts::Close { payer, cacli_acc }.to_account_metas(None),
+        data: calci::instruction::Close {}.data(),
+    }
+}
+
+#[tokio::test]
+async fn close_returns_rent_to_payer() {
+    let owner = Keypair::new();
+    let cacli_acc = Pubkey::new_unique();
+    let account = calci_account(calci::ID, 0, owner.pubkey());
+    let rent = account.lamports;
+    let (banks, payer) = start(Some((cacli_acc, account))).await;
+
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[close_instruction(owner.pubkey(), cacli_acc)],
+        Some(&payer.pubkey()),
+        &[&payer, &owner],
+        blockhash,
+    );
+    banks.process_transaction(tx).await.unwrap();
+
+    assert!(banks.get_account(cacli_acc).await.unwrap().is_none());
+    assert_eq!(banks.get_balance(owner.pubkey()).await.unwrap(), rent);
+}
+
+#[tokio::test]
+async fn closed_account_cannot_be_used() {
+    let owner = Keypair::new();
+    let cacli_acc = Pubkey::new_unique();
+    let account = calci_account(calci::ID, 0, owner.pubkey());
+    let (mut banks, payer) = start(Some((cacli_acc, account))).await;
+
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[close_instruction(owner.pubkey(), cacli_acc)],
+        Some(&payer.pubkey()),
+        &[&payer, &owner],
+        blockhash,
+    );
+    banks.process_transaction(tx).await.unwrap();
+
+    let ix = instruction(cacli_acc, calci::instruction::Add { a: 1, b: 2 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::AccountNotInitialized.into(),
+    );
+}
+
+#[tokio::test]
+async fn account_with_forged_discriminator_fails() {
+    let cacli_acc = Pubkey::new_unique();
+    let mut account = calci_account(calci::ID, 7, Pubkey::new_unique());
+    account.data[..8].fill(0);
+    let (mut banks, payer) = start(Some((cacli_acc, account))).await;
+
+    let ix = instruction(cacli_acc, calci::instruction::Add { a: 1, b: 2 });
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+    );
+}
+
+#[tokio::test]
+async fn close_by_non_payer_fails() {
+    let (mut banks, payer, cacli_acc) = start_initialized(7).await;
+
+    let ix = close_instruction(payer.pubkey(), cacli_acc);
+    let result = process(&mut banks, &payer, ix).await;
+    assert_custom_error(result, ErrorCode::NotPayer.into());
+    assert_eq!(stored_result(&mut banks, cacli_acc).await, 7);
+}
@@ -35,6 +35,22 @@ const program = anchor.workspace.Calci as Program<Calci>;
     );
   });
 
+  it("Fails to re-initialize the calculator account", async () => {
+    try {
+      await program.methods
+        .initialize()
+        .accounts({
+          feePayer: provider.wallet.publicKey,
+          cacliAcc: calciAcc.publicKey,
+        })
+        .signers([calciAcc])
+        .rpc();
+      assert.fail("Expected re-initialize to throw");
+    } catch (err: any) {
+      assert.include(err.toString(), "already in use");
+    }
+  });
+
   it("Performs addition", async () => {
     await program.methods
       .add(10, 20)
@@ -71,6 +87,47 @@ const program = anchor.workspace.Calci as Program<Calci>;
     assert.equal(account.calciResult, 20);
   });
 
+  it("Reads the result via return data", async () => {
+    const result = await program.methods
+      .getResult()
+      .accounts({
+        cacliAcc: calciAcc.publicKey,
+      })
+      .view();
+
+    assert.equal(result, 20);
+  });
+
+  it("Fails addition overflow", async () => {
+    try {
+      await program.methods
+        .add(200, 100)
+        .accounts({
+          cacliAcc: calciAcc.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected addition overflow to throw");
+    } catch (err: any) {
+      const errMsg = err.error.errorMessage;
+      assert.equal(errMsg, "Arithmetic overflow");
+    }
+  });
+
+  it("Fails subtraction underflow", async () => {
+    try {
+      await program.methods
+        .sub(15, 50)
+        .accounts({
+          cacliAcc: calciAcc.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected subtraction underflow to throw");
+    } catch (err: any) {
+      const errMsg = err.error.errorMessage;
+      assert.equal(errMsg, "Arithmetic underflow");
+    }
+  });
+
   it("Fails division by zero", async () => {
     try {
       await program.methods
@@ -85,4 +142,50 @@ const program = anchor.workspace.Calci as Program<Calci>;
       assert.equal(errMsg, "Division by zero is not allowed");
     }
   });
+
+  it("Fails close by non-payer", async () => {
+    const other = Keypair.generate();
+    try {
+      await program.methods
+        .close()
+        .accountsPartial({
+          payer: other.publicKey,
+          cacliAcc: calciAcc.publicKey,
+        })
+        .signers([other])
+        .rpc();
+      assert.fail("Expected close by non-payer to throw");
+    } catch (err: any) {
+      const errMsg = err.error.errorMessage;
+      assert.equal(errMsg, "Only the payer can close the calculator account");
+    }
+  });
+
+  it("Closes the calculator account", async () => {
+    await program.methods
+      .close()
+      .accounts({
+        cacliAcc: calciAcc.publicKey,
+      })
+      .rpc();
+
+    const account = await program.account.calciResult.fetchNullable(
+      calciAcc.publicKey
+    );
+    assert.isNull(account);
+  });
+
+  it("Fails operations on a closed account", async () => {
+    try {
+      await program.methods
+        .add(1, 2)
+        .accounts({
+          cacliAcc: calciAcc.publicKey,
+        })
+        .rpc();
+      assert.fail("Expected add on a closed account to throw");
+    } catch (err: any) {
+      assert.equal(err.error.errorCode.code, "AccountNotInitialized");
+    }
+  });
 });